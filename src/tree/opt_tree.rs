@@ -1,4 +1,8 @@
 use std::cmp::Ordering;
+use std::collections::TryReserveError;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
 use std::marker::PhantomData;
 use ::*;
 
@@ -17,6 +21,11 @@ use super::core::CoreTree;
 ///
 pub struct OptTree<'a, T: 'a> {
     pub(crate) core_tree: CoreTree<OptNode<T>, T>,
+    /// When set, `insert_under_node` keeps children ordered by this comparator instead of
+    /// always appending. Populated by `OptTreeBuilder::with_child_comparator`, which lives
+    /// on the builder (outside this file) and is out of scope here; this field is
+    /// `pub(crate)` only so this module's tests can set it directly in the meantime.
+    pub(crate) child_comparator: Option<Box<dyn Fn(&T, &T) -> Ordering>>,
     pub(crate) phantom: PhantomData<&'a T>,
 }
 
@@ -27,10 +36,9 @@ impl<'a, T> Tree<'a, T> for OptTree<'a, T> {
     type ChildrenIter = OptChildren<'a, T>;
     type ChildrenIdsIter = OptChildrenIds<'a, T>;
 
-    // todo: make real iterators for these.
-    type PreOrderIter = Ancestors<'a, OptTree<'a, T>, T>;
-    type PostOrderIter = Ancestors<'a, OptTree<'a, T>, T>;
-    type LevelOrderIter = Ancestors<'a, OptTree<'a, T>, T>;
+    type PreOrderIter = OptPreOrderTraversal<'a, T>;
+    type PostOrderIter = OptPostOrderTraversal<'a, T>;
+    type LevelOrderIter = OptLevelOrderTraversal<'a, T>;
 
     fn new() -> Self {
         OptTreeBuilder::new().build()
@@ -51,6 +59,7 @@ impl<'a, T> Tree<'a, T> for OptTree<'a, T> {
                 self.insert_under_node(node, parent_id)
             }
             InsertBehavior::AsRoot => Ok(self.set_root(node)),
+            InsertBehavior::AsAdditionalRoot => Ok(self.insert_additional_root(node)),
         }
     }
 
@@ -83,33 +92,136 @@ impl<'a, T> Tree<'a, T> for OptTree<'a, T> {
         node_id: NodeId,
         behavior: RemoveBehavior,
     ) -> Result<OptNode<T>, NodeIdError> {
-        unimplemented!()
+        self.core_tree.validate_node_id(&node_id)?;
+
+        match behavior {
+            RemoveBehavior::DropChildren => {
+                let children = self.child_ids_of(&node_id);
+                self.detach_node(&node_id);
+
+                for child_id in children {
+                    self.free_subtree(child_id);
+                }
+            }
+            RemoveBehavior::LiftChildren => {
+                let (parent, prev_sibling, next_sibling) = {
+                    let node = unsafe { self.get_unchecked(&node_id) };
+                    (
+                        node.parent().cloned(),
+                        node.prev_sibling().cloned(),
+                        node.next_sibling().cloned(),
+                    )
+                };
+                let children = self.child_ids_of(&node_id);
+
+                self.detach_node(&node_id);
+
+                for child_id in &children {
+                    let child = unsafe { self.get_unchecked_mut(child_id) };
+                    child.set_parent(parent.clone());
+                }
+
+                // The lifted children are already linked to one another; splice that
+                // chain into node_id's old slot (between its former prev/next siblings)
+                // instead of appending it, so removing a middle or last sibling doesn't
+                // silently reorder the tree.
+                if let (Some(first_id), Some(last_id)) =
+                    (children.first().cloned(), children.last().cloned())
+                {
+                    {
+                        let first_child = unsafe { self.get_unchecked_mut(&first_id) };
+                        first_child.set_prev_sibling(prev_sibling.clone());
+                    }
+                    {
+                        let last_child = unsafe { self.get_unchecked_mut(&last_id) };
+                        last_child.set_next_sibling(next_sibling.clone());
+                    }
+
+                    match prev_sibling {
+                        Some(ref prev_id) => {
+                            let prev_node = unsafe { self.get_unchecked_mut(prev_id) };
+                            prev_node.set_next_sibling(Some(first_id.clone()));
+                        }
+                        None => match parent {
+                            Some(ref parent_id) => {
+                                let parent_node = unsafe { self.get_unchecked_mut(parent_id) };
+                                parent_node.set_first_child(Some(first_id.clone()));
+                            }
+                            None => {
+                                self.core_tree.root = Some(first_id.clone());
+                            }
+                        },
+                    }
+
+                    match next_sibling {
+                        Some(ref next_id) => {
+                            let next_node = unsafe { self.get_unchecked_mut(next_id) };
+                            next_node.set_prev_sibling(Some(last_id.clone()));
+                        }
+                        None => {
+                            if let Some(ref parent_id) = parent {
+                                let parent_node = unsafe { self.get_unchecked_mut(parent_id) };
+                                parent_node.set_last_child(Some(last_id.clone()));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut freed = self.core_tree.nodes[node_id.index].take().expect(
+            "NodeId was validated above, so its slot must be occupied.",
+        );
+        self.core_tree.free_ids.push(node_id);
+
+        // By now `detach_node` has spliced `freed` out of its parent/sibling chain, and its
+        // child links point either at lifted children spliced elsewhere (LiftChildren) or
+        // at slots that were just zeroed and freed for reuse (DropChildren).
+        // Clear them so the returned node can't be mistaken for a live view of the tree.
+        freed.set_parent(None);
+        freed.set_first_child(None);
+        freed.set_last_child(None);
+        freed.set_prev_sibling(None);
+        freed.set_next_sibling(None);
+
+        Ok(freed)
     }
 
     fn move_node(&mut self, node_id: &NodeId, behavior: MoveBehavior) -> Result<(), NodeIdError> {
         unimplemented!()
     }
 
-    fn sort_children_by<F>(&mut self, node_id: &NodeId, compare: F) -> Result<(), NodeIdError>
+    fn sort_children_by<F>(&mut self, node_id: &NodeId, mut compare: F) -> Result<(), NodeIdError>
     where
         F: FnMut(&OptNode<T>, &OptNode<T>) -> Ordering,
     {
-        unimplemented!()
+        self.core_tree.validate_node_id(node_id)?;
+
+        let mut children = self.child_ids_of(node_id);
+        children.sort_by(|a, b| {
+            let node_a = unsafe { self.get_unchecked(a) };
+            let node_b = unsafe { self.get_unchecked(b) };
+            compare(node_a, node_b)
+        });
+
+        self.relink_children(node_id, children);
+
+        Ok(())
     }
 
     fn sort_children_by_data(&mut self, node_id: &NodeId) -> Result<(), NodeIdError>
     where
         T: Ord,
     {
-        unimplemented!()
+        self.sort_children_by(node_id, |a, b| a.data().cmp(b.data()))
     }
 
-    fn sort_children_by_key<K, F>(&mut self, node_id: &NodeId, f: F) -> Result<(), NodeIdError>
+    fn sort_children_by_key<K, F>(&mut self, node_id: &NodeId, mut f: F) -> Result<(), NodeIdError>
     where
         K: Ord,
         F: FnMut(&OptNode<T>) -> K,
     {
-        unimplemented!()
+        self.sort_children_by(node_id, |a, b| f(a).cmp(&f(b)))
     }
 
     fn swap_nodes(
@@ -132,30 +244,38 @@ impl<'a, T> Tree<'a, T> for OptTree<'a, T> {
     }
 
     fn ancestor_ids(&'a self, node_id: &NodeId) -> Result<Self::AncestorIdsIter, NodeIdError> {
-        unimplemented!()
+        self.core_tree.validate_node_id(node_id)?;
+        Ok(AncestorIds::new(self, node_id.clone()))
     }
 
     fn children(&'a self, node_id: &NodeId) -> Result<Self::ChildrenIter, NodeIdError> {
-        unimplemented!()
+        self.core_tree.validate_node_id(node_id)?;
+        let first_child = unsafe { self.get_unchecked(node_id) }.first_child().cloned();
+        Ok(OptChildren::new(self, first_child))
     }
 
     fn children_ids(&'a self, node_id: &NodeId) -> Result<Self::ChildrenIdsIter, NodeIdError> {
-        unimplemented!()
+        self.core_tree.validate_node_id(node_id)?;
+        let first_child = unsafe { self.get_unchecked(node_id) }.first_child().cloned();
+        Ok(OptChildrenIds::new(self, first_child))
     }
 
     fn traverse_pre_order(&'a self, node_id: &NodeId) -> Result<Self::PreOrderIter, NodeIdError> {
-        unimplemented!()
+        self.core_tree.validate_node_id(node_id)?;
+        Ok(OptPreOrderTraversal::new(self, node_id.clone()))
     }
 
     fn traverse_post_order(&'a self, node_id: &NodeId) -> Result<Self::PostOrderIter, NodeIdError> {
-        unimplemented!()
+        self.core_tree.validate_node_id(node_id)?;
+        Ok(OptPostOrderTraversal::new(self, node_id.clone()))
     }
 
     fn traverse_level_order(
         &'a self,
         node_id: &NodeId,
     ) -> Result<Self::LevelOrderIter, NodeIdError> {
-        unimplemented!()
+        self.core_tree.validate_node_id(node_id)?;
+        Ok(OptLevelOrderTraversal::new(self, node_id.clone()))
     }
 }
 
@@ -171,62 +291,758 @@ impl<'a, T> OptTree<'a, T> {
 
         node.set_parent(Some(parent_id.clone()));
 
+        if self.child_comparator.is_some() {
+            return Ok(self.insert_under_node_sorted(node, parent_id));
+        }
+
         let new_id = self.core_tree.insert(node);
+        self.link_as_last_child(parent_id, &new_id);
+
+        Ok(new_id)
+    }
 
-        let children = {
-            let parent = unsafe { self.get_unchecked(parent_id) };
-            (parent.first_child().cloned(), parent.last_child().cloned())
+    ///
+    /// Inserts `node` as a child of `parent_id`, keeping the sibling chain ordered
+    /// according to `self.child_comparator`.
+    ///
+    /// Walks the existing chain to find the first sibling that `child_comparator` reports
+    /// as greater than `node`, and splices the new node in just before it (in between that
+    /// sibling and its predecessor). Falls back to appending at the end if every sibling
+    /// compares less than or equal to `node`.
+    ///
+    fn insert_under_node_sorted(&mut self, node: OptNode<T>, parent_id: &NodeId) -> NodeId {
+        let insert_before = {
+            let comparator = self.child_comparator
+                .as_ref()
+                .expect("insert_under_node_sorted requires a child_comparator.");
+
+            let mut next_child = unsafe { self.get_unchecked(parent_id) }.first_child().cloned();
+            let mut found = None;
+
+            while let Some(child_id) = next_child {
+                let child = unsafe { self.get_unchecked(&child_id) };
+                if comparator(node.data(), child.data()) == Ordering::Less {
+                    found = Some(child_id);
+                    break;
+                }
+                next_child = child.next_sibling().cloned();
+            }
+
+            found
         };
 
-        match children {
-            (Some(_), Some(last_id)) => {
+        let new_id = self.core_tree.insert(node);
+
+        match insert_before {
+            Some(before_id) => {
+                let prev_id = unsafe { self.get_unchecked(&before_id) }.prev_sibling().cloned();
+
                 {
-                    let parent = unsafe { self.get_unchecked_mut(parent_id) };
-                    parent.set_last_child(Some(new_id.clone()));
+                    let new_node = unsafe { self.get_unchecked_mut(&new_id) };
+                    new_node.set_prev_sibling(prev_id.clone());
+                    new_node.set_next_sibling(Some(before_id.clone()));
                 }
 
                 {
-                    let new_node = unsafe { self.get_unchecked_mut(&new_id) };
-                    new_node.set_prev_sibling(Some(last_id.clone()));
+                    let before_node = unsafe { self.get_unchecked_mut(&before_id) };
+                    before_node.set_prev_sibling(Some(new_id.clone()));
                 }
 
-                let last_child = unsafe { self.get_unchecked_mut(&last_id) };
-                last_child.set_next_sibling(Some(new_id.clone()));
-            }
-            //todo: find a better error message for these.
-            (Some(_), None) => panic!("Found an OptNode in an invalid state."),
-            (None, Some(_)) => panic!("Found an OptNode in an invalid state."),
-            (None, None) => {
-                let parent = unsafe { self.get_unchecked_mut(parent_id) };
-                parent.set_first_child(Some(new_id.clone()));
-                parent.set_last_child(Some(new_id.clone()));
+                match prev_id {
+                    Some(ref prev_id) => {
+                        let prev_node = unsafe { self.get_unchecked_mut(prev_id) };
+                        prev_node.set_next_sibling(Some(new_id.clone()));
+                    }
+                    None => {
+                        let parent = unsafe { self.get_unchecked_mut(parent_id) };
+                        parent.set_first_child(Some(new_id.clone()));
+                    }
+                }
             }
+            None => self.link_as_last_child(parent_id, &new_id),
         }
 
-        Ok(new_id)
+        new_id
+    }
+
+    ///
+    /// Rebuilds the `first_child`/`last_child`/`prev_sibling`/`next_sibling` pointers of
+    /// `parent_id`'s children to match `children`'s order.
+    ///
+    fn relink_children(&mut self, parent_id: &NodeId, children: Vec<NodeId>) {
+        {
+            let parent = unsafe { self.get_unchecked_mut(parent_id) };
+            parent.set_first_child(children.first().cloned());
+            parent.set_last_child(children.last().cloned());
+        }
+
+        for (index, child_id) in children.iter().enumerate() {
+            let prev = if index == 0 {
+                None
+            } else {
+                Some(children[index - 1].clone())
+            };
+            let next = children.get(index + 1).cloned();
+
+            let child = unsafe { self.get_unchecked_mut(child_id) };
+            child.set_prev_sibling(prev);
+            child.set_next_sibling(next);
+        }
     }
 
     ///
     /// Sets the root of the `Tree`.
     ///
+    /// If the tree already had one or more roots (including any additional roots inserted
+    /// via `InsertBehavior::AsAdditionalRoot`), the *entire* existing root chain becomes the
+    /// new root's children, preserving the sibling links between them.
+    ///
     fn set_root(&mut self, new_root: OptNode<T>) -> NodeId {
 
-        let current_root = self.core_tree.root.clone();
+        let old_roots: Vec<NodeId> = {
+            let mut ids = Vec::new();
+            let mut current = self.core_tree.root.clone();
+
+            while let Some(id) = current {
+                current = unsafe { self.get_unchecked(&id) }.next_sibling().cloned();
+                ids.push(id);
+            }
+
+            ids
+        };
+
         let new_root_id = self.core_tree.set_root(new_root);
 
-        if let Some(current_root_id) = current_root {
-            {
-                let current_root = unsafe { self.get_unchecked_mut(&current_root_id) };
-                current_root.set_parent(Some(new_root_id.clone()));
+        if !old_roots.is_empty() {
+            for old_root_id in &old_roots {
+                let old_root = unsafe { self.get_unchecked_mut(old_root_id) };
+                old_root.set_parent(Some(new_root_id.clone()));
             }
 
             let root = unsafe { self.get_unchecked_mut(&new_root_id) };
-            root.set_first_child(Some(current_root_id.clone()));
-            root.set_last_child(Some(current_root_id.clone()));
+            root.set_first_child(old_roots.first().cloned());
+            root.set_last_child(old_roots.last().cloned());
         }
 
         new_root_id
     }
+
+    ///
+    /// Inserts `node` as an additional, independent root.
+    ///
+    /// Roots are linked together through the same `next_sibling`/`prev_sibling` chain used
+    /// for ordinary children, forming a virtual top-level sibling chain of nodes whose
+    /// `parent` is `None`. This lets a single `OptTree` hold a forest of disjoint trees
+    /// instead of forcing everything under one synthetic super-root.
+    ///
+    /// Note: this module matches on `InsertBehavior::AsAdditionalRoot` as though it already
+    /// exists; the variant itself is declared on `InsertBehavior`, which lives outside this
+    /// file (in the `behaviors` module) and is out of scope here.
+    ///
+    fn insert_additional_root(&mut self, node: OptNode<T>) -> NodeId {
+        let new_id = self.core_tree.insert(node);
+        self.link_as_last_root(&new_id);
+        new_id
+    }
+
+    ///
+    /// Returns an iterator over the `NodeId`s of every root currently in this `OptTree`.
+    ///
+    /// Walks the top-level sibling chain from the first root, so roots inserted with
+    /// `InsertBehavior::AsAdditionalRoot` come after it in insertion order. Yields nothing
+    /// for an empty tree.
+    ///
+    pub fn roots(&'a self) -> OptRoots<'a, T> {
+        OptRoots::new(self, self.core_tree.root())
+    }
+
+    ///
+    /// Like `insert`, but surfaces backing-store allocation failure as a `TreeError`
+    /// instead of panicking or aborting.
+    ///
+    /// Before growing `core_tree.nodes` to make room for the new node, capacity is
+    /// requested via `Vec::try_reserve`; if that fails, the tree is left untouched and
+    /// `TreeError::AllocFailed` is returned. This matters for users embedding `id-tree` in
+    /// memory-constrained or OOM-resilient contexts where a panic on allocation is
+    /// unacceptable.
+    ///
+    /// Note: this only covers insertion. A fallible `OptTreeBuilder::try_build()` (so that
+    /// `with_node_capacity`/`with_swap_capacity` pre-reservation can fail gracefully too)
+    /// would belong on `OptTreeBuilder` itself, which lives outside this module; it is
+    /// deliberately out of scope here.
+    ///
+    pub fn try_insert(
+        &mut self,
+        node: OptNode<T>,
+        behavior: InsertBehavior,
+    ) -> Result<NodeId, TreeError> {
+        // `InsertBehavior::AsRoot` goes through `core_tree.set_root`, a separate method on
+        // `CoreTree` (outside this module) whose free-id-reuse behavior isn't guaranteed to
+        // match `core_tree.insert`'s. Reserve unconditionally for that path rather than
+        // assuming it only grows `nodes` when `free_ids` is empty.
+        if matches!(behavior, InsertBehavior::AsRoot) || self.core_tree.free_ids.is_empty() {
+            self.core_tree.nodes.try_reserve(1)?;
+        }
+
+        match behavior {
+            InsertBehavior::UnderNode(parent_id) => {
+                self.core_tree.validate_node_id(parent_id)?;
+                Ok(self.insert_under_node(node, parent_id)?)
+            }
+            InsertBehavior::AsRoot => Ok(self.set_root(node)),
+            InsertBehavior::AsAdditionalRoot => Ok(self.insert_additional_root(node)),
+        }
+    }
+
+    ///
+    /// Collects the `NodeId`s of `node_id`'s children by walking its sibling chain.
+    ///
+    fn child_ids_of(&self, node_id: &NodeId) -> Vec<NodeId> {
+        let mut ids = Vec::new();
+        let mut next_child = unsafe { self.get_unchecked(node_id) }.first_child().cloned();
+
+        while let Some(child_id) = next_child {
+            let child = unsafe { self.get_unchecked(&child_id) };
+            next_child = child.next_sibling().cloned();
+            ids.push(child_id);
+        }
+
+        ids
+    }
+
+    ///
+    /// Unlinks `node_id` from its parent's child chain (or the root chain, if it has no
+    /// parent), splicing its neighbors together. Does not touch `node_id`'s own children;
+    /// callers are responsible for re-parenting or freeing them.
+    ///
+    fn detach_node(&mut self, node_id: &NodeId) {
+        let (parent, prev, next) = {
+            let node = unsafe { self.get_unchecked(node_id) };
+            (
+                node.parent().cloned(),
+                node.prev_sibling().cloned(),
+                node.next_sibling().cloned(),
+            )
+        };
+
+        match prev.clone() {
+            Some(ref prev_id) => {
+                let prev_node = unsafe { self.get_unchecked_mut(prev_id) };
+                prev_node.set_next_sibling(next.clone());
+            }
+            None => match parent {
+                Some(ref parent_id) => {
+                    let parent_node = unsafe { self.get_unchecked_mut(parent_id) };
+                    parent_node.set_first_child(next.clone());
+                }
+                None => {
+                    self.core_tree.root = next.clone();
+                }
+            },
+        }
+
+        match next {
+            Some(ref next_id) => {
+                let next_node = unsafe { self.get_unchecked_mut(next_id) };
+                next_node.set_prev_sibling(prev);
+            }
+            None => {
+                if let Some(ref parent_id) = parent {
+                    let parent_node = unsafe { self.get_unchecked_mut(parent_id) };
+                    parent_node.set_last_child(prev);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Links `child_id` in as the last child of `parent_id`, assuming `child_id` is not
+    /// currently linked into any chain.
+    ///
+    fn link_as_last_child(&mut self, parent_id: &NodeId, child_id: &NodeId) {
+        let last_child_id = unsafe { self.get_unchecked(parent_id) }.last_child().cloned();
+
+        match last_child_id {
+            Some(last_id) => {
+                {
+                    let parent = unsafe { self.get_unchecked_mut(parent_id) };
+                    parent.set_last_child(Some(child_id.clone()));
+                }
+                {
+                    let child = unsafe { self.get_unchecked_mut(child_id) };
+                    child.set_prev_sibling(Some(last_id.clone()));
+                }
+                let last_child = unsafe { self.get_unchecked_mut(&last_id) };
+                last_child.set_next_sibling(Some(child_id.clone()));
+            }
+            None => {
+                let parent = unsafe { self.get_unchecked_mut(parent_id) };
+                parent.set_first_child(Some(child_id.clone()));
+                parent.set_last_child(Some(child_id.clone()));
+            }
+        }
+    }
+
+    ///
+    /// Links `node_id` in as the last root of the forest, assuming it is not currently
+    /// linked into any chain.
+    ///
+    fn link_as_last_root(&mut self, node_id: &NodeId) {
+        match self.core_tree.root.clone() {
+            Some(first_root_id) => {
+                let mut last_root_id = first_root_id;
+                while let Some(next_id) =
+                    unsafe { self.get_unchecked(&last_root_id) }.next_sibling().cloned()
+                {
+                    last_root_id = next_id;
+                }
+
+                {
+                    let last_root = unsafe { self.get_unchecked_mut(&last_root_id) };
+                    last_root.set_next_sibling(Some(node_id.clone()));
+                }
+
+                let node = unsafe { self.get_unchecked_mut(node_id) };
+                node.set_prev_sibling(Some(last_root_id));
+            }
+            None => {
+                self.core_tree.root = Some(node_id.clone());
+            }
+        }
+    }
+
+    ///
+    /// Detaches `node_id`'s entire subtree (itself and all descendants) and pushes every
+    /// freed slot onto `core_tree.free_ids` for reuse. `node_id` must already be unlinked
+    /// from its parent/sibling chain.
+    ///
+    fn free_subtree(&mut self, node_id: NodeId) {
+        let mut stack = vec![node_id];
+
+        while let Some(current_id) = stack.pop() {
+            stack.extend(self.child_ids_of(&current_id));
+
+            self.core_tree.nodes[current_id.index] = None;
+            self.core_tree.free_ids.push(current_id);
+        }
+    }
+
+    ///
+    /// Counts `node_id` and all of its descendants without freeing anything.
+    ///
+    fn count_subtree(&self, node_id: &NodeId) -> usize {
+        let mut count = 0;
+        let mut stack = vec![node_id.clone()];
+
+        while let Some(current_id) = stack.pop() {
+            count += 1;
+            stack.extend(self.child_ids_of(&current_id));
+        }
+
+        count
+    }
+
+    ///
+    /// Runs `f` over every node reachable from the tree's roots in a single traversal,
+    /// acting on its `NodeAction` result.
+    ///
+    /// `NodeAction::Keep` continues into the node's children. `NodeAction::RemoveSubtree`
+    /// detaches the node and its entire descendant subtree, pushing their slots onto
+    /// `core_tree.free_ids` for reuse, without descending into it. `NodeAction::Error`
+    /// stops the traversal immediately; the offending `NodeId` is recorded in the returned
+    /// `ProcessOutcome`.
+    ///
+    pub fn process<F>(&mut self, mut f: F) -> ProcessOutcome
+    where
+        F: FnMut(&mut OptNode<T>) -> NodeAction,
+    {
+        let mut outcome = ProcessOutcome {
+            kept: 0,
+            removed: 0,
+            errors: Vec::new(),
+        };
+
+        let mut stack: Vec<NodeId> = {
+            let mut ids = Vec::new();
+            let mut current = self.core_tree.root.clone();
+
+            while let Some(id) = current {
+                current = unsafe { self.get_unchecked(&id) }.next_sibling().cloned();
+                ids.push(id);
+            }
+
+            // `ids` is in chain order (root1, root2, ...); reverse it so popping this
+            // LIFO stack visits the roots in that same left-to-right order.
+            ids.reverse();
+            ids
+        };
+
+        while let Some(node_id) = stack.pop() {
+            let action = {
+                let node = unsafe { self.get_unchecked_mut(&node_id) };
+                f(node)
+            };
+
+            match action {
+                NodeAction::Keep => {
+                    outcome.kept += 1;
+
+                    let mut next_child =
+                        unsafe { self.get_unchecked(&node_id) }.last_child().cloned();
+                    while let Some(child_id) = next_child {
+                        let child = unsafe { self.get_unchecked(&child_id) };
+                        next_child = child.prev_sibling().cloned();
+                        stack.push(child_id);
+                    }
+                }
+                NodeAction::RemoveSubtree => {
+                    outcome.removed += self.count_subtree(&node_id);
+                    self.detach_node(&node_id);
+                    self.free_subtree(node_id);
+                }
+                NodeAction::Error => {
+                    outcome.errors.push(node_id);
+                    break;
+                }
+            }
+        }
+
+        outcome
+    }
+
+    ///
+    /// Resolves `path` against `root`, descending one child per path segment.
+    ///
+    /// At each level, `key` extracts a `K` from a candidate child and that `K` is compared
+    /// against the corresponding `Q` in `path`; the first sibling (walking `first_child()`
+    /// then `next_sibling()`) whose key matches is descended into. Returns `None` as soon as
+    /// a segment has no matching child.
+    ///
+    pub fn get_by_path<K, Q, F>(
+        &'a self,
+        root: &'a NodeId,
+        path: &[Q],
+        mut key: F,
+    ) -> Option<&'a NodeId>
+    where
+        F: FnMut(&OptNode<T>) -> K,
+        K: PartialEq<Q>,
+    {
+        let mut current = root;
+
+        for segment in path {
+            let mut next_child = unsafe { self.get_unchecked(current) }.first_child();
+            let mut found = None;
+
+            while let Some(child_id) = next_child {
+                let child = unsafe { self.get_unchecked(child_id) };
+                if key(child) == *segment {
+                    found = Some(child_id);
+                    break;
+                }
+                next_child = child.next_sibling();
+            }
+
+            current = found?;
+        }
+
+        Some(current)
+    }
+
+    ///
+    /// Performs a post-order reduction over `node_id`'s subtree (`node_id` included).
+    ///
+    /// Every descendant is folded into `init` before `node_id` itself is, so `f` can depend
+    /// on values already computed for a node's children (e.g. summing directory sizes up
+    /// from their contents). Iterative, via an explicit stack, so it doesn't blow the call
+    /// stack on deep trees.
+    ///
+    pub fn fold_subtree<B, F>(&self, node_id: &NodeId, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &OptNode<T>) -> B,
+    {
+        let mut stack = vec![node_id.clone()];
+        let mut order = Vec::new();
+
+        while let Some(current_id) = stack.pop() {
+            stack.extend(self.child_ids_of(&current_id));
+            order.push(current_id);
+        }
+
+        order.iter().rev().fold(init, |acc, id| {
+            let node = unsafe { self.get_unchecked(id) };
+            f(acc, node)
+        })
+    }
+}
+
+///
+/// The outcome a `process` callback can produce for a single node.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeAction {
+    /// Leave the node and its subtree in place and continue the traversal into its children.
+    Keep,
+    /// Detach the node and its entire descendant subtree, freeing their slots for reuse.
+    RemoveSubtree,
+    /// Stop the traversal immediately; the node is reported in `ProcessOutcome::errors`.
+    Error,
+}
+
+///
+/// The result of a single `OptTree::process` pass.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessOutcome {
+    /// Number of nodes the callback asked to `Keep`.
+    pub kept: usize,
+    /// Number of nodes freed as a result of `RemoveSubtree` actions, including descendants
+    /// removed along with the node the callback was actually called on.
+    pub removed: usize,
+    /// Nodes for which the callback returned `NodeAction::Error`, in traversal order.
+    pub errors: Vec<NodeId>,
+}
+
+///
+/// An `Iterator` over the roots of an `OptTree`, yielding `&NodeId` values.
+///
+/// Walks the virtual top-level sibling chain of root nodes via `next_sibling()`.
+///
+pub struct OptRoots<'a, T: 'a> {
+    tree: &'a OptTree<'a, T>,
+    node_id: Option<&'a NodeId>,
+}
+
+impl<'a, T> OptRoots<'a, T> {
+    pub(crate) fn new(tree: &'a OptTree<'a, T>, node_id: Option<&'a NodeId>) -> OptRoots<'a, T> {
+        OptRoots { tree, node_id }
+    }
+}
+
+impl<'a, T> Iterator for OptRoots<'a, T> {
+    type Item = &'a NodeId;
+
+    fn next(&mut self) -> Option<&'a NodeId> {
+        let node_id = self.node_id.take()?;
+        let node = unsafe { self.tree.get_unchecked(node_id) };
+        self.node_id = node.next_sibling();
+        Some(node_id)
+    }
+}
+
+///
+/// An `Iterator` over the children of an `OptTree` node, yielding `&OptNode<T>` values.
+///
+/// Walks the intrusive sibling chain starting at `first_child()` and following
+/// `next_sibling()` until it runs out, without allocating anything beyond the cursor
+/// itself.
+///
+pub struct OptChildren<'a, T: 'a> {
+    tree: &'a OptTree<'a, T>,
+    node_id: Option<NodeId>,
+}
+
+impl<'a, T> OptChildren<'a, T> {
+    pub(crate) fn new(tree: &'a OptTree<'a, T>, node_id: Option<NodeId>) -> OptChildren<'a, T> {
+        OptChildren { tree, node_id }
+    }
+}
+
+impl<'a, T> Iterator for OptChildren<'a, T> {
+    type Item = &'a OptNode<T>;
+
+    fn next(&mut self) -> Option<&'a OptNode<T>> {
+        let node_id = self.node_id.take()?;
+        let node = unsafe { self.tree.get_unchecked(&node_id) };
+        self.node_id = node.next_sibling().cloned();
+        Some(node)
+    }
+}
+
+///
+/// An `Iterator` over the children of an `OptTree` node, yielding `NodeId` values.
+///
+/// Behaves like `OptChildren` but yields the `NodeId` of each sibling instead of a
+/// reference to the `OptNode` itself.
+///
+pub struct OptChildrenIds<'a, T: 'a> {
+    tree: &'a OptTree<'a, T>,
+    node_id: Option<NodeId>,
+}
+
+impl<'a, T> OptChildrenIds<'a, T> {
+    pub(crate) fn new(tree: &'a OptTree<'a, T>, node_id: Option<NodeId>) -> OptChildrenIds<'a, T> {
+        OptChildrenIds { tree, node_id }
+    }
+}
+
+impl<'a, T> Iterator for OptChildrenIds<'a, T> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        let node_id = self.node_id.take()?;
+        let node = unsafe { self.tree.get_unchecked(&node_id) };
+        self.node_id = node.next_sibling().cloned();
+        Some(node_id)
+    }
+}
+
+///
+/// An `Iterator` over the nodes of an `OptTree` in pre-order (parent before children).
+///
+/// Keeps an explicit stack of `NodeId`s instead of recursing. Children are pushed onto
+/// the stack from `last_child()` back to `first_child()` (following `prev_sibling()`) so
+/// that popping the stack yields them left-to-right.
+///
+pub struct OptPreOrderTraversal<'a, T: 'a> {
+    tree: &'a OptTree<'a, T>,
+    stack: Vec<NodeId>,
+}
+
+impl<'a, T> OptPreOrderTraversal<'a, T> {
+    pub(crate) fn new(tree: &'a OptTree<'a, T>, node_id: NodeId) -> OptPreOrderTraversal<'a, T> {
+        OptPreOrderTraversal {
+            tree,
+            stack: vec![node_id],
+        }
+    }
+}
+
+impl<'a, T> Iterator for OptPreOrderTraversal<'a, T> {
+    type Item = &'a OptNode<T>;
+
+    fn next(&mut self) -> Option<&'a OptNode<T>> {
+        let node_id = self.stack.pop()?;
+        let node = unsafe { self.tree.get_unchecked(&node_id) };
+
+        let mut next_child = node.last_child().cloned();
+        while let Some(child_id) = next_child {
+            let child = unsafe { self.tree.get_unchecked(&child_id) };
+            next_child = child.prev_sibling().cloned();
+            self.stack.push(child_id);
+        }
+
+        Some(node)
+    }
+}
+
+///
+/// An `Iterator` over the nodes of an `OptTree` in post-order (children before parent).
+///
+/// Built eagerly from the same explicit-stack technique as `OptPreOrderTraversal`: nodes
+/// are popped off a work stack (pushing their children in left-to-right order) onto a
+/// result stack, which naturally comes out in post-order when popped from the back.
+///
+pub struct OptPostOrderTraversal<'a, T: 'a> {
+    tree: &'a OptTree<'a, T>,
+    result: Vec<&'a OptNode<T>>,
+}
+
+impl<'a, T> OptPostOrderTraversal<'a, T> {
+    pub(crate) fn new(tree: &'a OptTree<'a, T>, node_id: NodeId) -> OptPostOrderTraversal<'a, T> {
+        let mut work_stack = vec![node_id];
+        let mut result = Vec::new();
+
+        while let Some(current_id) = work_stack.pop() {
+            let node = unsafe { tree.get_unchecked(&current_id) };
+            result.push(node);
+
+            let mut next_child = node.first_child().cloned();
+            while let Some(child_id) = next_child {
+                let child = unsafe { tree.get_unchecked(&child_id) };
+                next_child = child.next_sibling().cloned();
+                work_stack.push(child_id);
+            }
+        }
+
+        OptPostOrderTraversal { tree, result }
+    }
+}
+
+impl<'a, T> Iterator for OptPostOrderTraversal<'a, T> {
+    type Item = &'a OptNode<T>;
+
+    fn next(&mut self) -> Option<&'a OptNode<T>> {
+        self.result.pop()
+    }
+}
+
+///
+/// An `Iterator` over the nodes of an `OptTree` in level-order (breadth-first).
+///
+/// Keeps a `VecDeque` of `NodeId`s; each node's children are enqueued (following its
+/// sibling chain) as soon as the node itself is dequeued.
+///
+pub struct OptLevelOrderTraversal<'a, T: 'a> {
+    tree: &'a OptTree<'a, T>,
+    queue: VecDeque<NodeId>,
+}
+
+impl<'a, T> OptLevelOrderTraversal<'a, T> {
+    pub(crate) fn new(tree: &'a OptTree<'a, T>, node_id: NodeId) -> OptLevelOrderTraversal<'a, T> {
+        let mut queue = VecDeque::new();
+        queue.push_back(node_id);
+        OptLevelOrderTraversal { tree, queue }
+    }
+}
+
+impl<'a, T> Iterator for OptLevelOrderTraversal<'a, T> {
+    type Item = &'a OptNode<T>;
+
+    fn next(&mut self) -> Option<&'a OptNode<T>> {
+        let node_id = self.queue.pop_front()?;
+        let node = unsafe { self.tree.get_unchecked(&node_id) };
+
+        let mut next_child = node.first_child().cloned();
+        while let Some(child_id) = next_child {
+            let child = unsafe { self.tree.get_unchecked(&child_id) };
+            next_child = child.next_sibling().cloned();
+            self.queue.push_back(child_id);
+        }
+
+        Some(node)
+    }
+}
+
+///
+/// An error returned by a fallible `OptTree` operation, such as `try_insert`.
+///
+#[derive(Debug)]
+pub enum TreeError {
+    /// The given `NodeId` was invalid for this tree.
+    InvalidNodeId(NodeIdError),
+    /// The backing store could not grow to accommodate the operation.
+    AllocFailed(TryReserveError),
+}
+
+impl fmt::Display for TreeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TreeError::InvalidNodeId(ref error) => write!(f, "{}", error),
+            TreeError::AllocFailed(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for TreeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            TreeError::InvalidNodeId(ref error) => Some(error),
+            TreeError::AllocFailed(ref error) => Some(error),
+        }
+    }
+}
+
+impl From<NodeIdError> for TreeError {
+    fn from(error: NodeIdError) -> TreeError {
+        TreeError::InvalidNodeId(error)
+    }
+}
+
+impl From<TryReserveError> for TreeError {
+    fn from(error: TryReserveError) -> TreeError {
+        TreeError::AllocFailed(error)
+    }
 }
 
 #[cfg(test)]
@@ -382,4 +1198,344 @@ mod opt_tree_tests {
         assert_eq!(tree.get(&child_2).unwrap().prev_sibling(), Some(&child_1));
         assert_eq!(tree.get(&child_2).unwrap().next_sibling(), None);
     }
+
+    fn new_populated_tree<'a>() -> (NodeId, Vec<NodeId>, OptTree<'a, i32>) {
+        let (root_id, mut tree) = new_tree();
+
+        let child_1 = tree.insert(Node::new(2), UnderNode(&root_id)).unwrap();
+        let child_2 = tree.insert(Node::new(3), UnderNode(&root_id)).unwrap();
+        let grandchild = tree.insert(Node::new(4), UnderNode(&child_1)).unwrap();
+
+        (root_id, vec![child_1, child_2, grandchild], tree)
+    }
+
+    #[test]
+    fn children() {
+        let (root_id, ids, tree) = new_populated_tree();
+
+        let data: Vec<i32> = tree
+            .children(&root_id)
+            .unwrap()
+            .map(|node| *node.data())
+            .collect();
+
+        assert_eq!(data, vec![2, 3]);
+        assert_eq!(tree.children(&ids[2]).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn children_ids() {
+        let (root_id, ids, tree) = new_populated_tree();
+
+        let children: Vec<NodeId> = tree.children_ids(&root_id).unwrap().collect();
+
+        assert_eq!(children, vec![ids[0].clone(), ids[1].clone()]);
+    }
+
+    #[test]
+    fn traverse_pre_order() {
+        let (root_id, _ids, tree) = new_populated_tree();
+
+        let data: Vec<i32> = tree
+            .traverse_pre_order(&root_id)
+            .unwrap()
+            .map(|node| *node.data())
+            .collect();
+
+        assert_eq!(data, vec![1, 2, 4, 3]);
+    }
+
+    #[test]
+    fn traverse_post_order() {
+        let (root_id, _ids, tree) = new_populated_tree();
+
+        let data: Vec<i32> = tree
+            .traverse_post_order(&root_id)
+            .unwrap()
+            .map(|node| *node.data())
+            .collect();
+
+        assert_eq!(data, vec![4, 2, 3, 1]);
+    }
+
+    #[test]
+    fn as_additional_root() {
+        let (root_id, mut tree) = new_tree();
+
+        let second_root = tree.insert(Node::new(2), AsAdditionalRoot).unwrap();
+        let third_root = tree.insert(Node::new(3), AsAdditionalRoot).unwrap();
+
+        let roots: Vec<NodeId> = tree.roots().cloned().collect();
+        assert_eq!(roots, vec![root_id.clone(), second_root.clone(), third_root.clone()]);
+
+        assert_eq!(tree.get(&root_id).unwrap().parent(), None);
+        assert_eq!(tree.get(&second_root).unwrap().parent(), None);
+        assert_eq!(tree.get(&third_root).unwrap().parent(), None);
+    }
+
+    #[test]
+    fn as_root_after_additional_roots() {
+        let (root_a, mut tree) = new_tree();
+
+        let root_b = tree.insert(Node::new(2), AsAdditionalRoot).unwrap();
+        let root_c = tree.insert(Node::new(3), AsAdditionalRoot).unwrap();
+
+        let root_d = tree.insert(Node::new(4), AsRoot).unwrap();
+
+        let roots: Vec<NodeId> = tree.roots().cloned().collect();
+        assert_eq!(roots, vec![root_d.clone()]);
+
+        assert_eq!(tree.get(&root_d).unwrap().parent(), None);
+        assert_eq!(tree.get(&root_d).unwrap().first_child(), Some(&root_a));
+        assert_eq!(tree.get(&root_d).unwrap().last_child(), Some(&root_c));
+
+        let children: Vec<NodeId> = tree.children_ids(&root_d).unwrap().collect();
+        assert_eq!(children, vec![root_a.clone(), root_b.clone(), root_c.clone()]);
+
+        assert_eq!(tree.get(&root_a).unwrap().parent(), Some(&root_d));
+        assert_eq!(tree.get(&root_b).unwrap().parent(), Some(&root_d));
+        assert_eq!(tree.get(&root_c).unwrap().parent(), Some(&root_d));
+    }
+
+    #[test]
+    fn remove_drop_children() {
+        let (root_id, ids, mut tree) = new_populated_tree();
+
+        let removed = tree.remove(ids[0].clone(), ::behaviors::RemoveBehavior::DropChildren)
+            .unwrap();
+        assert_eq!(*removed.data(), 2);
+        assert_eq!(removed.parent(), None);
+        assert_eq!(removed.first_child(), None);
+        assert_eq!(removed.last_child(), None);
+        assert_eq!(removed.prev_sibling(), None);
+        assert_eq!(removed.next_sibling(), None);
+
+        assert!(tree.get(&ids[0]).is_err());
+        assert!(tree.get(&ids[2]).is_err());
+        assert_eq!(tree.get(&root_id).unwrap().first_child(), Some(&ids[1]));
+        assert_eq!(tree.get(&root_id).unwrap().last_child(), Some(&ids[1]));
+    }
+
+    #[test]
+    fn remove_lift_children() {
+        let (root_id, ids, mut tree) = new_populated_tree();
+
+        // ids[0] (data 2) is the root's first child, with ids[2] (data 4) as its only
+        // child; lifting should leave ids[2] in ids[0]'s old slot, ahead of ids[1].
+        let removed = tree.remove(ids[0].clone(), ::behaviors::RemoveBehavior::LiftChildren)
+            .unwrap();
+        assert_eq!(removed.parent(), None);
+        assert_eq!(removed.first_child(), None);
+        assert_eq!(removed.last_child(), None);
+        assert_eq!(removed.prev_sibling(), None);
+        assert_eq!(removed.next_sibling(), None);
+
+        assert_eq!(tree.get(&ids[2]).unwrap().parent(), Some(&root_id));
+        assert_eq!(tree.get(&root_id).unwrap().first_child(), Some(&ids[2]));
+        assert_eq!(tree.get(&root_id).unwrap().last_child(), Some(&ids[1]));
+        assert_eq!(tree.get(&ids[2]).unwrap().next_sibling(), Some(&ids[1]));
+    }
+
+    #[test]
+    fn remove_lift_children_preserves_position() {
+        let (root_id, mut tree) = new_tree();
+
+        let child_1 = tree.insert(Node::new(2), UnderNode(&root_id)).unwrap();
+        let child_2 = tree.insert(Node::new(3), UnderNode(&root_id)).unwrap();
+        let child_3 = tree.insert(Node::new(4), UnderNode(&root_id)).unwrap();
+
+        let grandchild_a = tree.insert(Node::new(5), UnderNode(&child_2)).unwrap();
+        let grandchild_b = tree.insert(Node::new(6), UnderNode(&child_2)).unwrap();
+
+        // child_2 sits in the middle of the sibling list; lifting its children should
+        // splice them into child_2's old slot, not append them after child_3.
+        tree.remove(child_2.clone(), ::behaviors::RemoveBehavior::LiftChildren)
+            .unwrap();
+
+        let children: Vec<NodeId> = tree.children_ids(&root_id).unwrap().collect();
+        assert_eq!(
+            children,
+            vec![
+                child_1.clone(),
+                grandchild_a.clone(),
+                grandchild_b.clone(),
+                child_3.clone(),
+            ]
+        );
+
+        assert_eq!(tree.get(&grandchild_a).unwrap().parent(), Some(&root_id));
+        assert_eq!(tree.get(&grandchild_b).unwrap().parent(), Some(&root_id));
+        assert_eq!(tree.get(&root_id).unwrap().first_child(), Some(&child_1));
+        assert_eq!(tree.get(&root_id).unwrap().last_child(), Some(&child_3));
+    }
+
+    #[test]
+    fn process_removes_subtree_and_frees_slots() {
+        let (root_id, ids, mut tree) = new_populated_tree();
+
+        let outcome = tree.process(|node| {
+            if *node.data() == 2 {
+                NodeAction::RemoveSubtree
+            } else {
+                NodeAction::Keep
+            }
+        });
+
+        assert_eq!(outcome.kept, 2);
+        assert_eq!(outcome.removed, 2);
+        assert!(outcome.errors.is_empty());
+
+        assert!(tree.get(&ids[0]).is_err());
+        assert!(tree.get(&ids[2]).is_err());
+        assert_eq!(tree.get(&root_id).unwrap().first_child(), Some(&ids[1]));
+        assert!(tree.core_tree.free_ids.contains(&ids[0]));
+        assert!(tree.core_tree.free_ids.contains(&ids[2]));
+    }
+
+    #[test]
+    fn process_visits_additional_roots_in_chain_order() {
+        let (_root_id, mut tree) = new_tree();
+        tree.insert(Node::new(2), AsAdditionalRoot).unwrap();
+        tree.insert(Node::new(3), AsAdditionalRoot).unwrap();
+
+        let mut visited = Vec::new();
+        tree.process(|node| {
+            visited.push(*node.data());
+            NodeAction::Keep
+        });
+
+        assert_eq!(visited, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn process_stops_on_error() {
+        let (_root_id, ids, mut tree) = new_populated_tree();
+
+        let outcome = tree.process(|node| {
+            if *node.data() == 4 {
+                NodeAction::Error
+            } else {
+                NodeAction::Keep
+            }
+        });
+
+        assert_eq!(outcome.errors, vec![ids[2].clone()]);
+    }
+
+    #[test]
+    fn get_by_path() {
+        let (root_id, ids, tree) = new_populated_tree();
+
+        let found = tree
+            .get_by_path(&root_id, &[2, 4], |node| *node.data())
+            .unwrap();
+        assert_eq!(found, &ids[2]);
+
+        assert_eq!(
+            tree.get_by_path(&root_id, &[2], |node| *node.data()),
+            Some(&ids[0])
+        );
+        assert_eq!(
+            tree.get_by_path(&root_id, &[9], |node| *node.data()),
+            None
+        );
+        assert_eq!(
+            tree.get_by_path(&root_id, &[2, 9], |node| *node.data()),
+            None
+        );
+    }
+
+    #[test]
+    fn fold_subtree() {
+        let (root_id, _ids, tree) = new_populated_tree();
+
+        let sum = tree.fold_subtree(&root_id, 0, |acc, node| acc + *node.data());
+
+        assert_eq!(sum, 1 + 2 + 3 + 4);
+    }
+
+    #[test]
+    fn insert_under_node_sorted() {
+        let (root_id, mut tree) = new_tree();
+        tree.child_comparator = Some(Box::new(|a: &i32, b: &i32| a.cmp(b)));
+
+        tree.insert(Node::new(5), UnderNode(&root_id)).unwrap();
+        tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+        tree.insert(Node::new(3), UnderNode(&root_id)).unwrap();
+
+        let data: Vec<i32> = tree
+            .children(&root_id)
+            .unwrap()
+            .map(|node| *node.data())
+            .collect();
+
+        assert_eq!(data, vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn sort_children_by_descending() {
+        let (root_id, _ids, mut tree) = new_populated_tree();
+
+        tree.sort_children_by(&root_id, |a, b| b.data().cmp(a.data()))
+            .unwrap();
+
+        let data: Vec<i32> = tree
+            .children(&root_id)
+            .unwrap()
+            .map(|node| *node.data())
+            .collect();
+
+        assert_eq!(data, vec![3, 2]);
+    }
+
+    #[test]
+    fn sort_children_by_data() {
+        let (root_id, _ids, mut tree) = new_populated_tree();
+
+        tree.insert(Node::new(0), UnderNode(&root_id)).unwrap();
+        tree.sort_children_by_data(&root_id).unwrap();
+
+        let data: Vec<i32> = tree
+            .children(&root_id)
+            .unwrap()
+            .map(|node| *node.data())
+            .collect();
+
+        assert_eq!(data, vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn try_insert() {
+        let (root_id, mut tree) = new_tree();
+
+        let child = tree.try_insert(Node::new(2), UnderNode(&root_id)).unwrap();
+
+        assert_eq!(tree.get(&child).unwrap().data(), &2);
+        assert_eq!(tree.get(&root_id).unwrap().first_child(), Some(&child));
+    }
+
+    #[test]
+    fn try_insert_invalid_parent() {
+        let (root_id, mut tree) = new_tree();
+
+        tree.remove(root_id.clone(), ::behaviors::RemoveBehavior::DropChildren)
+            .unwrap();
+
+        let result = tree.try_insert(Node::new(2), UnderNode(&root_id));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn traverse_level_order() {
+        let (root_id, _ids, tree) = new_populated_tree();
+
+        let data: Vec<i32> = tree
+            .traverse_level_order(&root_id)
+            .unwrap()
+            .map(|node| *node.data())
+            .collect();
+
+        assert_eq!(data, vec![1, 2, 3, 4]);
+    }
 }
\ No newline at end of file